@@ -0,0 +1,17 @@
+use bevy::ecs::component::Component;
+use bevy::prelude::*;
+
+/// Despawns every entity carrying the marker component `T`, along with its
+/// children. Used to tear down a screen (a menu, the splash screen, the
+/// pause overlay, ...) by registering it against that screen's marker, e.g.
+/// `despawn_screen::<MainMenu>`.
+///
+/// # Arguments
+///
+/// * `commands` - The commands used to modify the `World`.
+/// * `query` - The entities carrying the marker component `T`.
+pub fn despawn_screen<T: Component>(mut commands: Commands, query: Query<Entity, With<T>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,49 @@
+use crate::despawn::despawn_screen;
+use crate::states::GameState;
+use bevy::prelude::*;
+
+mod pause;
+
+pub use pause::InGameState;
+
+pub struct GamePlugin;
+
+impl Plugin for GamePlugin {
+    /// Called when the `App` registers the plugin to set up the in-game
+    /// pause substate and the systems that run while `MainGame` is active.
+    ///
+    /// # Arguments
+    ///
+    /// * `app` - The main Bevy app instance.
+    fn build(&self, app: &mut AppBuilder) {
+        // `InGameState` only matters while `MainGame` is the active
+        // `GameState`, but it's tracked independently so pausing doesn't
+        // tear down the world the way leaving `MainGame` would.
+        app.add_state(InGameState::Running);
+
+        // While playing, watch for the player pressing Escape to pause.
+        app.add_system_set(
+            SystemSet::on_update(GameState::MainGame)
+                .with_system(pause::handle_pause_input.system()),
+        );
+
+        // When the game is paused, build the pause overlay.
+        app.add_system_set(
+            SystemSet::on_enter(InGameState::Paused)
+                .with_system(pause::setup_pause_overlay.system()),
+        )
+        // While paused, read clicks on the "Resume" and "Quit to Menu"
+        // buttons and give them the same hover/pressed visuals as the rest
+        // of the menus.
+        .add_system_set(
+            SystemSet::on_update(InGameState::Paused)
+                .with_system(pause::handle_pause_button_interaction.system())
+                .with_system(crate::menus::button_visuals.system()),
+        )
+        // When the game is unpaused, tear down the pause overlay.
+        .add_system_set(
+            SystemSet::on_exit(InGameState::Paused)
+                .with_system(despawn_screen::<pause::PauseOverlay>.system()),
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use crate::menus::ButtonMaterials;
+use crate::states::GameState;
+use bevy::ecs::component::Component;
+use bevy::prelude::*;
+
+/// Whether the game is actively being played or paused. This only has any
+/// effect while `GameState::MainGame` is active; it's tracked separately so
+/// that pausing doesn't tear down the game world the way leaving
+/// `MainGame` would.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum InGameState {
+    Running,
+    Paused,
+}
+
+/// Represents the pause overlay and the entities that make it up.
+pub struct PauseOverlay;
+
+/// Represents the button that resumes the game.
+pub struct ResumeButton;
+
+/// Represents the button that quits the game back to the main menu.
+pub struct QuitButton;
+
+/// Watches for the player pressing Escape while playing and, if they do,
+/// pauses the game.
+///
+/// # Arguments
+///
+/// * `keyboard_input` - The state of the keyboard.
+/// * `in_game_state` - The current pause state of the game.
+pub fn handle_pause_input(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut in_game_state: ResMut<State<InGameState>>,
+) {
+    let is_running = *in_game_state.current() == InGameState::Running;
+
+    if is_running && keyboard_input.just_pressed(KeyCode::Escape) {
+        in_game_state
+            .set(InGameState::Paused)
+            .map_err(|err| error!("Failed to pause game: {}", err))
+            .unwrap();
+    }
+}
+
+/// Sets up the pause overlay by spawning a translucent background along with
+/// the "Resume" and "Quit to Menu" buttons. The game world behind it is left
+/// untouched.
+///
+/// # Arguments
+///
+/// * `commands` - A list of commands that will be run to modify a `World`.
+/// * `asset_server` - Used to load our custom font.
+/// * `color_materials` - Used to build the overlay's translucent background material.
+/// * `button_materials` - The cached material to give the Resume/Quit buttons their initial look.
+pub fn setup_pause_overlay(
+    mut commands: Commands,
+    asset_server: ResMut<AssetServer>,
+    mut color_materials: ResMut<Assets<ColorMaterial>>,
+    button_materials: Res<ButtonMaterials>,
+) {
+    let font: Handle<Font> = asset_server.load("fonts/RobotoMono-Regular.ttf");
+
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                },
+                flex_direction: FlexDirection::ColumnReverse,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceEvenly,
+                ..Style::default()
+            },
+            material: color_materials.add(Color::rgba(0.0, 0.0, 0.0, 0.75).into()),
+            ..NodeBundle::default()
+        })
+        .insert(PauseOverlay)
+        .with_children(|parent| {
+            parent.spawn_bundle(TextBundle {
+                text: Text::with_section(
+                    "Paused",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 40.0,
+                        color: Color::WHITE,
+                    },
+                    TextAlignment {
+                        vertical: VerticalAlign::Center,
+                        horizontal: HorizontalAlign::Center,
+                    },
+                ),
+                ..TextBundle::default()
+            });
+
+            spawn_pause_button(
+                parent,
+                font.clone(),
+                &button_materials,
+                "Resume",
+                ResumeButton,
+            );
+            spawn_pause_button(
+                parent,
+                font.clone(),
+                &button_materials,
+                "Quit to Menu",
+                QuitButton,
+            );
+        });
+}
+
+/// Spawns a single button within the pause overlay.
+///
+/// # Arguments
+///
+/// * `parent` - The parent which we can use to spawn the button with.
+/// * `font` - The font to use for the button text.
+/// * `button_materials` - The cached material to give the button its initial look.
+/// * `label` - The text to show on the button.
+/// * `marker` - The component that identifies which button this is.
+fn spawn_pause_button<T: Component>(
+    parent: &mut ChildBuilder,
+    font: Handle<Font>,
+    button_materials: &ButtonMaterials,
+    label: &str,
+    marker: T,
+) {
+    parent
+        .spawn_bundle(ButtonBundle {
+            style: Style {
+                size: Size {
+                    width: Val::Percent(15.0),
+                    height: Val::Px(30.0),
+                },
+                flex_direction: FlexDirection::ColumnReverse,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceEvenly,
+                ..Style::default()
+            },
+            material: button_materials.normal.clone(),
+            ..ButtonBundle::default()
+        })
+        .insert(marker)
+        .with_children(|parent| {
+            parent.spawn_bundle(TextBundle {
+                text: Text::with_section(
+                    label,
+                    TextStyle {
+                        font,
+                        font_size: 20.0,
+                        color: Color::DARK_GRAY,
+                    },
+                    TextAlignment {
+                        vertical: VerticalAlign::Center,
+                        horizontal: HorizontalAlign::Center,
+                    },
+                ),
+                ..TextBundle::default()
+            });
+        });
+}
+
+/// Reads clicks on the "Resume" and "Quit to Menu" buttons. "Resume" returns
+/// the game to `InGameState::Running`; "Quit to Menu" pops the `GameState`
+/// stack back to `MainMenu`.
+///
+/// # Arguments
+///
+/// * `app_state` - The current `GameState` of the game.
+/// * `in_game_state` - The current pause state of the game.
+/// * `resume_query` - The query for the "Resume" button.
+/// * `quit_query` - The query for the "Quit to Menu" button.
+pub fn handle_pause_button_interaction(
+    mut app_state: ResMut<State<GameState>>,
+    mut in_game_state: ResMut<State<InGameState>>,
+    resume_query: Query<&Interaction, With<ResumeButton>>,
+    quit_query: Query<&Interaction, With<QuitButton>>,
+) {
+    resume_query.for_each(|interaction| {
+        if *interaction == Interaction::Clicked {
+            in_game_state
+                .set(InGameState::Running)
+                .map_err(|err| error!("Failed to resume game: {}", err))
+                .unwrap();
+        }
+    });
+
+    quit_query.for_each(|interaction| {
+        if *interaction == Interaction::Clicked {
+            // Unpause first so the overlay is torn down along with the rest
+            // of the `MainGame` world instead of being left on top of the
+            // main menu forever.
+            in_game_state
+                .set(InGameState::Running)
+                .map_err(|err| error!("Failed to resume game: {}", err))
+                .unwrap();
+
+            app_state
+                .pop()
+                .map_err(|err| error!("Failed to quit to menu: {}", err))
+                .unwrap();
+        }
+    });
+}
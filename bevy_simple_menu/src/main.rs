@@ -1,6 +1,13 @@
+// Bevy's `Query` filters routinely exceed clippy's type-complexity threshold;
+// breaking them into aliases would only hide what the ECS query actually
+// selects, so the lint is silenced crate-wide as Bevy's own examples do.
+#![allow(clippy::type_complexity)]
+
 use bevy::{prelude::*, window::WindowMode};
 
 mod camera;
+mod despawn;
+mod game;
 mod menus;
 mod states;
 
@@ -23,15 +30,21 @@ fn main() {
         ..Default::default()
     });
 
+    // Add the default settings the player starts the game with.
+    app.insert_resource(menus::DisplayQuality::Medium)
+        .insert_resource(menus::Volume(7));
+
     // Add the plugins we need.
     app.add_plugins(DefaultPlugins)
-        .add_plugin(menus::MenusPlugin);
+        .add_plugin(menus::MenusPlugin)
+        .add_plugin(game::GamePlugin);
 
     // Add the camera as a startup system.
     app.add_startup_system(camera::spawn_ui_camera.system());
 
-    // Add the starting state. We want the user to start at the main menu.
-    app.add_state(states::GameState::MainMenu);
+    // Add the starting state. We want the user to see the splash screen
+    // before landing on the main menu.
+    app.add_state(states::GameState::Splash);
 
     // Start the game.
     app.run();
@@ -0,0 +1,61 @@
+use super::settings::SelectedOption;
+use bevy::prelude::*;
+
+/// The background color of a button while the cursor isn't interacting with
+/// it.
+const NORMAL_BUTTON: Color = Color::rgb(0.15, 0.15, 0.15);
+/// The background color of a button while the cursor is hovering over it.
+const HOVERED_BUTTON: Color = Color::rgb(0.25, 0.25, 0.25);
+/// The background color of a button while it's being pressed.
+const PRESSED_BUTTON: Color = Color::rgb(0.35, 0.75, 0.35);
+
+/// Cached `ColorMaterial` handles for each button visual state, so systems
+/// can give a button its feedback color by cloning a handle instead of
+/// reaching into `Assets<ColorMaterial>` every frame.
+pub struct ButtonMaterials {
+    pub normal: Handle<ColorMaterial>,
+    pub hovered: Handle<ColorMaterial>,
+    pub pressed: Handle<ColorMaterial>,
+}
+
+/// Builds the `ButtonMaterials` resource once at startup.
+///
+/// # Arguments
+///
+/// * `commands` - Used to insert the `ButtonMaterials` resource.
+/// * `materials` - Used to turn the button colors into `ColorMaterial` assets.
+pub fn setup_button_materials(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    commands.insert_resource(ButtonMaterials {
+        normal: materials.add(NORMAL_BUTTON.into()),
+        hovered: materials.add(HOVERED_BUTTON.into()),
+        pressed: materials.add(PRESSED_BUTTON.into()),
+    });
+}
+
+/// Gives every button its hover and pressed visual feedback by setting its
+/// material to match its current `Interaction` each frame. Buttons marked
+/// `SelectedOption` (the settings menu's quality/volume picks) are skipped
+/// so their selection highlight isn't overwritten.
+///
+/// # Arguments
+///
+/// * `button_materials` - The cached materials for each visual state.
+/// * `query` - The buttons to update the material of.
+pub fn button_visuals(
+    button_materials: Res<ButtonMaterials>,
+    mut query: Query<
+        (&Interaction, &mut Handle<ColorMaterial>),
+        (Changed<Interaction>, With<Button>, Without<SelectedOption>),
+    >,
+) {
+    for (interaction, mut material) in query.iter_mut() {
+        *material = match interaction {
+            Interaction::Clicked => button_materials.pressed.clone(),
+            Interaction::Hovered => button_materials.hovered.clone(),
+            Interaction::None => button_materials.normal.clone(),
+        };
+    }
+}
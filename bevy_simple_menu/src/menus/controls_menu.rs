@@ -1,3 +1,4 @@
+use super::button::ButtonMaterials;
 use crate::states::GameState;
 use bevy::prelude::*;
 
@@ -14,7 +15,12 @@ pub struct BackButton;
 ///
 /// * `commands` - A list of commands that will be run to modify a `World`.
 /// * `asset_server` - Used to load assets from the filesystem on background threads.
-pub fn setup_controls_menu(mut commands: Commands, asset_server: ResMut<AssetServer>) {
+/// * `button_materials` - The cached material to give the Back button its initial look.
+pub fn setup_controls_menu(
+    mut commands: Commands,
+    asset_server: ResMut<AssetServer>,
+    button_materials: Res<ButtonMaterials>,
+) {
     // Load our custom font.
     let font: Handle<Font> = asset_server.load("fonts/RobotoMono-Regular.ttf");
 
@@ -66,6 +72,7 @@ pub fn setup_controls_menu(mut commands: Commands, asset_server: ResMut<AssetSer
                         justify_content: JustifyContent::SpaceEvenly,
                         ..Style::default()
                     },
+                    material: button_materials.normal.clone(),
                     ..ButtonBundle::default()
                 })
                 // Adds the "Back" button to return the user to the game.
@@ -91,19 +98,6 @@ pub fn setup_controls_menu(mut commands: Commands, asset_server: ResMut<AssetSer
         });
 }
 
-/// Tears down the controls menu by removing all entities that are part of the
-/// controls menu.
-///
-/// # Arguments
-///
-/// * `commands` - The commands used to modify the `World`.
-/// * `query` - The controls menu query.
-pub fn teardown_controls_menu(mut commands: Commands, query: Query<Entity, With<ControlMenu>>) {
-    for entity in query.iter() {
-        commands.entity(entity).despawn_recursive();
-    }
-}
-
 /// When the Back button is clicked we pop the `ControlsMenu` state so that the
 /// game goes back to the `MainMenu` state.
 ///
@@ -128,9 +122,9 @@ pub fn handle_back_button_interaction(
                 .unwrap();
         }
 
-        // Hover effects can be applied here.
+        // Hover and pressed visuals are handled separately by
+        // `button::button_visuals`.
         Interaction::Hovered => {}
-        // Catch all for interactions.
         Interaction::None => {}
     });
 }
@@ -1,3 +1,4 @@
+use super::button::ButtonMaterials;
 use crate::states::GameState;
 use bevy::app::AppExit;
 use bevy::prelude::*;
@@ -12,6 +13,8 @@ pub enum MenuItem {
     Play,
     /// The controls button is used to open the controls menu.
     Controls,
+    /// The settings button is used to open the settings menu.
+    Settings,
     /// The exit button is used to exit the game.
     Exit,
 }
@@ -24,10 +27,12 @@ pub enum MenuItem {
 /// `commands` - Used to create the menu.
 /// `asset_server` - Used to load our custom font.
 /// `clear_color` - Used to create the solid background color for the main menu.
+/// `button_materials` - The cached materials the buttons are spawned with.
 pub fn setup_main_menu(
     mut commands: Commands,
     asset_server: ResMut<AssetServer>,
     mut clear_color: ResMut<ClearColor>,
+    button_materials: Res<ButtonMaterials>,
 ) {
     // Load our custom font.
     let font: Handle<Font> = asset_server.load("fonts/RobotoMono-Regular.ttf");
@@ -62,7 +67,7 @@ pub fn setup_main_menu(
         })
         .insert(MainMenu)
         // Next, we add in the title and buttons for the main menu.
-        .with_children(|mut parent| {
+        .with_children(|parent| {
             // Starting with the title. We'll just set our title to be the same as
             // the game title but with a larger font, and white to stick out on the
             // black background.
@@ -85,9 +90,10 @@ pub fn setup_main_menu(
 
             // Our buttons to spawn. This will show as an error until we define the
             // function but we'll do it next.
-            spawn_button(&mut parent, font.clone(), MenuItem::Play);
-            spawn_button(&mut parent, font.clone(), MenuItem::Controls);
-            spawn_button(&mut parent, font.clone(), MenuItem::Exit);
+            spawn_button(parent, font.clone(), &button_materials, MenuItem::Play);
+            spawn_button(parent, font.clone(), &button_materials, MenuItem::Controls);
+            spawn_button(parent, font.clone(), &button_materials, MenuItem::Settings);
+            spawn_button(parent, font.clone(), &button_materials, MenuItem::Exit);
         });
 }
 
@@ -97,8 +103,14 @@ pub fn setup_main_menu(
 ///
 /// * `parent` - The parent which we can use to spawn the buttons with.
 /// * `font` - The font to use for the button text.
+/// * `button_materials` - The cached material to give the button its initial look.
 /// * `item` - The `MenuItem` to spawn a button for.
-fn spawn_button(parent: &mut ChildBuilder, font: Handle<Font>, menu_item: MenuItem) {
+fn spawn_button(
+    parent: &mut ChildBuilder,
+    font: Handle<Font>,
+    button_materials: &ButtonMaterials,
+    menu_item: MenuItem,
+) {
     // Create the container for the button. This is more or less the same
     // properties as the menu layout.
     parent
@@ -115,6 +127,7 @@ fn spawn_button(parent: &mut ChildBuilder, font: Handle<Font>, menu_item: MenuIt
                 justify_content: JustifyContent::SpaceEvenly,
                 ..Style::default()
             },
+            material: button_materials.normal.clone(),
             ..ButtonBundle::default()
         })
         .insert(menu_item)
@@ -129,6 +142,7 @@ fn spawn_button(parent: &mut ChildBuilder, font: Handle<Font>, menu_item: MenuIt
                     match menu_item {
                         MenuItem::Play => "Play",
                         MenuItem::Controls => "Controls",
+                        MenuItem::Settings => "Settings",
                         MenuItem::Exit => "Exit",
                     },
                     // If you decided to use a custom font you can pass it here
@@ -182,26 +196,21 @@ pub fn handle_menu_item_click(
                     .map_err(|err| error!("Failed to open control menu: {}", err))
                     .unwrap();
             }
+            // When the settings button is clicked, we push the
+            // `SettingsMenu` state to open the settings menu.
+            MenuItem::Settings => {
+                app_state
+                    .push(GameState::SettingsMenu)
+                    .map_err(|err| error!("Failed to open settings menu: {}", err))
+                    .unwrap();
+            }
             // When the exit button is clicked, we send the `AppExit` event to
             // exit the application.
             MenuItem::Exit => app_exit_events.send(AppExit),
         },
-        // Optionally, if you're interesting in adding hover effects to the
-        // buttons, you can do so here.
+        // Hover and pressed visuals are handled separately by
+        // `button::button_visuals`.
         Interaction::Hovered => {}
-        _ => {}
+        Interaction::None => {}
     });
 }
-
-/// Tears down the main menu by removing all entities that are part of the
-/// main menu.
-///
-/// # Arguments
-///
-/// * `commands` - The commands used to modify the `World`.
-/// * `query` - The query to get the main menu and its entities.
-pub fn teardown_menu_items(mut commands: Commands, query: Query<Entity, With<MainMenu>>) {
-    for entity in query.iter() {
-        commands.entity(entity).despawn_recursive();
-    }
-}
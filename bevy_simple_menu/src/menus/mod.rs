@@ -1,8 +1,15 @@
+use crate::despawn::despawn_screen;
 use crate::states::GameState;
 use bevy::prelude::*;
 
+mod button;
 mod controls_menu;
 mod main_menu;
+mod settings;
+mod splash;
+
+pub use button::{button_visuals, ButtonMaterials};
+pub use settings::{DisplayQuality, Volume};
 
 pub struct MenusPlugin;
 
@@ -14,6 +21,28 @@ impl Plugin for MenusPlugin {
     ///
     /// * `app` - The main Bevy app instance.
     fn build(&self, app: &mut AppBuilder) {
+        // Build the cached button and settings-option materials once before
+        // any menu spawns a button that needs them.
+        app.add_startup_system(button::setup_button_materials.system())
+            .add_startup_system(settings::setup_option_materials.system());
+        // When the game state enters the `Splash` state, we build the splash
+        // screen.
+        app.add_system_set(
+            SystemSet::on_enter(GameState::Splash)
+                .with_system(splash::setup_splash.system()),
+        )
+        // While the splash screen is up, tick its timer so we know when to
+        // move on to the main menu.
+        .add_system_set(
+            SystemSet::on_update(GameState::Splash)
+                .with_system(splash::update_splash.system()),
+        )
+        // When the game state exits the `Splash` state, we tear down the
+        // splash screen.
+        .add_system_set(
+            SystemSet::on_exit(GameState::Splash)
+                .with_system(despawn_screen::<splash::SplashScreen>.system()),
+        );
         // When the game state enters the `MainMenu` state, we build the main
         // menu.
         app.add_system_set(
@@ -27,22 +56,23 @@ impl Plugin for MenusPlugin {
                 .with_system(main_menu::setup_main_menu.system()),
         )
         // When the game updates, we set the interactions for the main menu
-        // buttons.
+        // buttons and their hover/pressed visuals.
         .add_system_set(
             SystemSet::on_update(GameState::MainMenu)
-                .with_system(main_menu::handle_menu_item_click.system()),
+                .with_system(main_menu::handle_menu_item_click.system())
+                .with_system(button::button_visuals.system()),
         )
         // When the game state is paused in the `MainMenu` state, we tear down
         // the main menu.
         .add_system_set(
             SystemSet::on_pause(GameState::MainMenu)
-                .with_system(main_menu::teardown_menu_items.system()),
+                .with_system(despawn_screen::<main_menu::MainMenu>.system()),
         )
         // When the game state exists the `MainMenu` state, we tear down the
         // main menu.
         .add_system_set(
             SystemSet::on_exit(GameState::MainMenu)
-                .with_system(main_menu::teardown_menu_items.system()),
+                .with_system(despawn_screen::<main_menu::MainMenu>.system()),
         );
         // When the game state enters the `ControlMenu` state, we build the
         // controls menu.
@@ -50,16 +80,41 @@ impl Plugin for MenusPlugin {
             SystemSet::on_enter(GameState::ControlMenu)
                 .with_system(controls_menu::setup_controls_menu.system()),
         )
-        // Add the interaction for the Back button in the controls menu.
+        // Add the interaction for the Back button in the controls menu and
+        // its hover/pressed visuals.
         .add_system_set(
             SystemSet::on_update(GameState::ControlMenu)
-                .with_system(controls_menu::handle_back_button_interaction.system()),
+                .with_system(controls_menu::handle_back_button_interaction.system())
+                .with_system(button::button_visuals.system()),
         )
         // When the game state exits the `ControlMenu` state, we tear down the
         // controls menu.
         .add_system_set(
             SystemSet::on_exit(GameState::ControlMenu)
-                .with_system(controls_menu::teardown_controls_menu.system()),
+                .with_system(despawn_screen::<controls_menu::ControlMenu>.system()),
+        );
+        // When the game state enters the `SettingsMenu` state, we build the
+        // settings menu.
+        app.add_system_set(
+            SystemSet::on_enter(GameState::SettingsMenu)
+                .with_system(settings::setup_settings_menu.system()),
+        )
+        // When the game updates, we read clicks on the display quality and
+        // volume buttons and the Back button in the settings menu, and give
+        // the Back button the same hover/pressed visuals as the rest of the
+        // menus (the quality/volume buttons keep their own selection
+        // highlight instead, since `button_visuals` skips `SelectedOption`).
+        .add_system_set(
+            SystemSet::on_update(GameState::SettingsMenu)
+                .with_system(settings::handle_settings_button_interaction.system())
+                .with_system(settings::handle_back_button_interaction.system())
+                .with_system(button::button_visuals.system()),
+        )
+        // When the game state exits the `SettingsMenu` state, we tear down
+        // the settings menu.
+        .add_system_set(
+            SystemSet::on_exit(GameState::SettingsMenu)
+                .with_system(despawn_screen::<settings::SettingsMenu>.system()),
         );
     }
 }
@@ -0,0 +1,359 @@
+use super::button::ButtonMaterials;
+use crate::states::GameState;
+use bevy::ecs::component::Component;
+use bevy::prelude::*;
+
+/// The quality of the game's visuals.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum DisplayQuality {
+    Low,
+    Medium,
+    High,
+}
+
+/// How loud the game's audio is, from `0` to `9`.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct Volume(pub u32);
+
+/// Represents the settings menu of the game.
+pub struct SettingsMenu;
+
+/// Represents the button that returns the player to the main menu.
+pub struct BackButton;
+
+/// A button that sets `DisplayQuality` to the quality it carries when
+/// clicked.
+pub struct DisplayQualityButton(DisplayQuality);
+
+/// A button that sets `Volume` to the level it carries when clicked.
+pub struct VolumeButton(u32);
+
+/// Marks whichever button matches the currently active resource value so it
+/// can be highlighted differently than the rest.
+pub struct SelectedOption;
+
+/// The color a settings button is drawn with while it isn't selected.
+const NORMAL_OPTION: Color = Color::rgb(0.15, 0.15, 0.15);
+/// The color a settings button is drawn with while it is the active option.
+const SELECTED_OPTION: Color = Color::rgb(0.35, 0.75, 0.35);
+
+/// Cached `ColorMaterial` handles for the settings menu's quality/volume
+/// option buttons, mirroring `button::ButtonMaterials` but using the
+/// selection-highlight colors instead of hover/press feedback.
+pub(crate) struct OptionMaterials {
+    normal: Handle<ColorMaterial>,
+    selected: Handle<ColorMaterial>,
+}
+
+/// Builds the `OptionMaterials` resource once at startup.
+///
+/// # Arguments
+///
+/// * `commands` - Used to insert the `OptionMaterials` resource.
+/// * `materials` - Used to turn the option colors into `ColorMaterial` assets.
+pub fn setup_option_materials(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    commands.insert_resource(OptionMaterials {
+        normal: materials.add(NORMAL_OPTION.into()),
+        selected: materials.add(SELECTED_OPTION.into()),
+    });
+}
+
+/// Sets up the settings menu by defining the layout and spawning one row of
+/// buttons for `DisplayQuality` and one row of buttons for `Volume`.
+///
+/// # Arguments
+///
+/// * `commands` - A list of commands that will be run to modify a `World`.
+/// * `asset_server` - Used to load our custom font.
+/// * `display_quality` - The currently active display quality.
+/// * `volume` - The currently active volume.
+/// * `button_materials` - The cached material to give the Back button its initial look.
+/// * `option_materials` - The cached materials to give the option buttons their initial look.
+pub fn setup_settings_menu(
+    mut commands: Commands,
+    asset_server: ResMut<AssetServer>,
+    display_quality: Res<DisplayQuality>,
+    volume: Res<Volume>,
+    button_materials: Res<ButtonMaterials>,
+    option_materials: Res<OptionMaterials>,
+) {
+    // Load our custom font.
+    let font: Handle<Font> = asset_server.load("fonts/RobotoMono-Regular.ttf");
+
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                },
+                flex_direction: FlexDirection::ColumnReverse,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceEvenly,
+                ..Style::default()
+            },
+            visible: Visible {
+                is_visible: false,
+                ..Visible::default()
+            },
+            ..NodeBundle::default()
+        })
+        .insert(SettingsMenu)
+        .with_children(|parent| {
+            // The row of buttons that let the player pick a `DisplayQuality`.
+            parent
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::SpaceEvenly,
+                        ..Style::default()
+                    },
+                    ..NodeBundle::default()
+                })
+                .with_children(|parent| {
+                    for quality in [
+                        DisplayQuality::Low,
+                        DisplayQuality::Medium,
+                        DisplayQuality::High,
+                    ] {
+                        spawn_option_button(
+                            parent,
+                            font.clone(),
+                            &option_materials,
+                            match quality {
+                                DisplayQuality::Low => "Low",
+                                DisplayQuality::Medium => "Medium",
+                                DisplayQuality::High => "High",
+                            },
+                            DisplayQualityButton(quality),
+                            quality == *display_quality,
+                        );
+                    }
+                });
+
+            // The row of buttons that let the player pick a `Volume`.
+            parent
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::SpaceEvenly,
+                        ..Style::default()
+                    },
+                    ..NodeBundle::default()
+                })
+                .with_children(|parent| {
+                    for level in 0..=9 {
+                        spawn_option_button(
+                            parent,
+                            font.clone(),
+                            &option_materials,
+                            &level.to_string(),
+                            VolumeButton(level),
+                            level == volume.0,
+                        );
+                    }
+                });
+
+            parent
+                .spawn_bundle(ButtonBundle {
+                    style: Style {
+                        size: Size {
+                            width: Val::Percent(10.0),
+                            height: Val::Px(30.0),
+                        },
+                        flex_direction: FlexDirection::ColumnReverse,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::SpaceEvenly,
+                        ..Style::default()
+                    },
+                    material: button_materials.normal.clone(),
+                    ..ButtonBundle::default()
+                })
+                // Adds the "Back" button to return the user to the main menu.
+                .insert(BackButton)
+                .with_children(|parent| {
+                    parent.spawn_bundle(TextBundle {
+                        style: Style::default(),
+                        text: Text::with_section(
+                            "Back",
+                            TextStyle {
+                                font,
+                                font_size: 20.0,
+                                color: Color::DARK_GRAY,
+                            },
+                            TextAlignment {
+                                vertical: VerticalAlign::Center,
+                                horizontal: HorizontalAlign::Center,
+                            },
+                        ),
+                        ..TextBundle::default()
+                    });
+                });
+        });
+}
+
+/// Spawns a single option button, inserting `T` as the value it carries and
+/// marking it with `SelectedOption` if it's the currently active value.
+///
+/// # Arguments
+///
+/// * `parent` - The parent which we can use to spawn the button with.
+/// * `font` - The font to use for the button text.
+/// * `option_materials` - The cached materials to give the button its initial look.
+/// * `label` - The text to show on the button.
+/// * `value` - The component that identifies what the button sets when clicked.
+/// * `is_selected` - Whether this button represents the currently active value.
+fn spawn_option_button<T: Component>(
+    parent: &mut ChildBuilder,
+    font: Handle<Font>,
+    option_materials: &OptionMaterials,
+    label: &str,
+    value: T,
+    is_selected: bool,
+) {
+    let mut button = parent.spawn_bundle(ButtonBundle {
+        style: Style {
+            size: Size {
+                width: Val::Px(40.0),
+                height: Val::Px(30.0),
+            },
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..Style::default()
+        },
+        material: if is_selected {
+            option_materials.selected.clone()
+        } else {
+            option_materials.normal.clone()
+        },
+        ..ButtonBundle::default()
+    });
+    button.insert(value);
+
+    if is_selected {
+        button.insert(SelectedOption);
+    }
+
+    button.with_children(|parent| {
+        parent.spawn_bundle(TextBundle {
+            style: Style::default(),
+            text: Text::with_section(
+                label,
+                TextStyle {
+                    font,
+                    font_size: 16.0,
+                    color: Color::WHITE,
+                },
+                TextAlignment {
+                    vertical: VerticalAlign::Center,
+                    horizontal: HorizontalAlign::Center,
+                },
+            ),
+            ..TextBundle::default()
+        });
+    });
+}
+
+/// Reads clicks on the `DisplayQuality` and `Volume` option buttons, updates
+/// the matching resource, and moves the `SelectedOption` marker (along with
+/// its highlight color) onto the button that was clicked. The quality row
+/// and the volume row each track their own selected button, so picking an
+/// option in one row never disturbs the highlight in the other.
+///
+/// # Arguments
+///
+/// * `commands` - Used to add/remove the `SelectedOption` marker.
+/// * `display_quality` - The resource to overwrite when a quality button is clicked.
+/// * `volume` - The resource to overwrite when a volume button is clicked.
+/// * `option_materials` - The cached materials an option button is recolored with.
+/// * `interaction_query` - The settings buttons that were interacted with.
+/// * `selected_quality_query` - The quality button that's currently marked as selected.
+/// * `selected_volume_query` - The volume button that's currently marked as selected.
+pub fn handle_settings_button_interaction(
+    mut commands: Commands,
+    mut display_quality: ResMut<DisplayQuality>,
+    mut volume: ResMut<Volume>,
+    option_materials: Res<OptionMaterials>,
+    interaction_query: Query<
+        (
+            Entity,
+            &Interaction,
+            Option<&DisplayQualityButton>,
+            Option<&VolumeButton>,
+        ),
+        (Changed<Interaction>, With<Button>),
+    >,
+    selected_quality_query: Query<
+        (Entity, &mut Handle<ColorMaterial>),
+        (With<SelectedOption>, With<DisplayQualityButton>),
+    >,
+    selected_volume_query: Query<
+        (Entity, &mut Handle<ColorMaterial>),
+        (With<SelectedOption>, With<VolumeButton>),
+    >,
+) {
+    for (entity, interaction, quality_button, volume_button) in interaction_query.iter() {
+        if *interaction != Interaction::Clicked {
+            continue;
+        }
+
+        if let Some(DisplayQualityButton(quality)) = quality_button {
+            *display_quality = *quality;
+            select_option(&mut commands, &option_materials, entity, selected_quality_query);
+            return;
+        }
+
+        if let Some(VolumeButton(level)) = volume_button {
+            *volume = Volume(*level);
+            select_option(&mut commands, &option_materials, entity, selected_volume_query);
+            return;
+        }
+    }
+}
+
+/// Moves the `SelectedOption` marker and its highlight color from whichever
+/// button in `selected_query`'s row currently holds it onto `entity`. The
+/// newly-selected button's material is set explicitly here rather than left
+/// for `button::button_visuals` to paint in passing.
+fn select_option<T: Component>(
+    commands: &mut Commands,
+    option_materials: &OptionMaterials,
+    entity: Entity,
+    mut selected_query: Query<(Entity, &mut Handle<ColorMaterial>), (With<SelectedOption>, With<T>)>,
+) {
+    for (previous_entity, mut material) in selected_query.iter_mut() {
+        *material = option_materials.normal.clone();
+        commands.entity(previous_entity).remove::<SelectedOption>();
+    }
+
+    commands.entity(entity).insert(option_materials.selected.clone());
+    commands.entity(entity).insert(SelectedOption);
+}
+
+/// When the Back button is clicked, we pop the `SettingsMenu` state so the
+/// game goes back to the `MainMenu` state.
+///
+/// # Arguments
+///
+/// * `app_state` - The state of the app.
+/// * `query` - The query for the back button.
+pub fn handle_back_button_interaction(
+    mut app_state: ResMut<State<GameState>>,
+    query: Query<&Interaction, With<BackButton>>,
+) {
+    query.for_each(|interaction| match interaction {
+        Interaction::Clicked => {
+            app_state
+                .pop()
+                .map_err(|err| error!("Failed to return to main menu: {}", err))
+                .unwrap();
+        }
+        Interaction::Hovered => {}
+        Interaction::None => {}
+    });
+}
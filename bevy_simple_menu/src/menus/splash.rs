@@ -0,0 +1,79 @@
+use crate::states::GameState;
+use bevy::prelude::*;
+
+/// Marker component for the entities that make up the splash screen.
+pub struct SplashScreen;
+
+/// Tracks how long the splash screen has been on display so we know when to
+/// move on to the main menu.
+pub struct SplashTimer(Timer);
+
+/// Sets up the splash screen by spawning the centered logo and starting the
+/// timer that will move the game on to the main menu.
+///
+/// # Arguments
+///
+/// * `commands` - A list of commands that will be run to modify a `World`.
+/// * `asset_server` - Used to load the logo image from the filesystem.
+/// * `materials` - Used to wrap the loaded logo texture in a `ColorMaterial`.
+pub fn setup_splash(
+    mut commands: Commands,
+    asset_server: ResMut<AssetServer>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let logo: Handle<Texture> = asset_server.load("branding/logo.png");
+
+    commands
+        // This is where we're going to define the layout of the splash
+        // screen.
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                // The splash screen takes up the whole window so the logo
+                // can be centered within it.
+                size: Size {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                },
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..Style::default()
+            },
+            ..NodeBundle::default()
+        })
+        .insert(SplashScreen)
+        .with_children(|parent| {
+            parent.spawn_bundle(ImageBundle {
+                style: Style {
+                    size: Size::new(Val::Px(200.0), Val::Auto),
+                    ..Style::default()
+                },
+                material: materials.add(logo.into()),
+                ..ImageBundle::default()
+            });
+        });
+
+    // Give the splash screen a second and a half before moving on to the
+    // main menu.
+    commands.insert_resource(SplashTimer(Timer::from_seconds(1.5, false)));
+}
+
+/// Ticks the splash timer and, once it's finished, moves the game on to the
+/// main menu.
+///
+/// # Arguments
+///
+/// * `app_state` - The current state of the game.
+/// * `time` - Used to advance the splash timer.
+/// * `timer` - The timer tracking how long the splash screen has been shown.
+pub fn update_splash(
+    mut app_state: ResMut<State<GameState>>,
+    time: Res<Time>,
+    mut timer: ResMut<SplashTimer>,
+) {
+    if timer.0.tick(time.delta()).finished() {
+        app_state
+            .set(GameState::MainMenu)
+            .map_err(|err| error!("Failed to leave splash screen: {}", err))
+            .unwrap();
+    }
+}
@@ -0,0 +1,14 @@
+/// Represents the various states that the game can be in.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum GameState {
+    /// The splash screen that displays briefly before the main menu.
+    Splash,
+    /// The main menu of the game.
+    MainMenu,
+    /// The menu that displays the controls of the game.
+    ControlMenu,
+    /// The menu that lets the player configure display and volume settings.
+    SettingsMenu,
+    /// The game itself.
+    MainGame,
+}